@@ -1,15 +1,28 @@
 use ethnum::U256;
 
+mod swap_math;
+pub use swap_math::*;
+
+mod tick_bitmap;
+pub use tick_bitmap::*;
+
+mod tick_math;
+pub use tick_math::*;
+
+mod tick;
+pub use tick::*;
+
 pub type ErrorCode = u16;
 
 pub const ARITHMETIC_OVERFLOW: ErrorCode = 9003;
 pub const AMOUNT_EXCEEDS_MAX_U64: ErrorCode = 9004;
+pub const INVALID_TICK_RANGE: ErrorCode = 9006;
 
 /// Get the initializable tick index.
 /// If the tick index is already initializable, it is returned as is.
 ///
 /// # Parameters
-/// - `tick_index` - A i32 integer representing the tick integer
+/// - `tick_index` - A i32 integer representing the tick integer, must be within `[MIN_TICK, MAX_TICK]`
 /// - `tick_spacing` - A i32 integer representing the tick spacing
 /// - `round_up` - A boolean value indicating if the supplied tick index should be rounded up. None will round to the nearest.
 ///
@@ -19,7 +32,11 @@ pub fn get_initializable_tick_index(
     tick_index: i32,
     tick_spacing: u16,
     round_up: Option<bool>,
-) -> i32 {
+) -> Result<i32, ErrorCode> {
+    if !(MIN_TICK..=MAX_TICK).contains(&tick_index) {
+        return Err(TICK_OUT_OF_BOUNDS);
+    }
+
     let tick_spacing_i32 = tick_spacing as i32;
     let remainder = tick_index % tick_spacing_i32;
     let result = tick_index / tick_spacing_i32 * tick_spacing_i32;
@@ -30,11 +47,50 @@ pub fn get_initializable_tick_index(
         remainder >= tick_spacing_i32 / 2
     };
 
-    if should_round_up {
-        result + tick_spacing_i32
+    let result = if should_round_up {
+        result.checked_add(tick_spacing_i32).ok_or(ARITHMETIC_OVERFLOW)?
     } else {
         result
+    };
+
+    if !(MIN_TICK..=MAX_TICK).contains(&result) {
+        return Err(TICK_OUT_OF_BOUNDS);
     }
+
+    Ok(result)
+}
+
+/// Validate that a tick range is well-formed for the given tick spacing.
+///
+/// # Parameters
+/// - `tick_lower_index` - A i32 integer representing the lower tick index of the range
+/// - `tick_upper_index` - A i32 integer representing the upper tick index of the range
+/// - `tick_spacing` - A u16 integer representing the tick spacing
+///
+/// # Returns
+/// - `Ok(())` if the range is valid, otherwise an `ErrorCode` describing why it is not
+pub fn validate_tick_range(
+    tick_lower_index: i32,
+    tick_upper_index: i32,
+    tick_spacing: u16,
+) -> Result<(), ErrorCode> {
+    if tick_lower_index >= tick_upper_index {
+        return Err(INVALID_TICK_RANGE);
+    }
+
+    if !(MIN_TICK..=MAX_TICK).contains(&tick_lower_index)
+        || !(MIN_TICK..=MAX_TICK).contains(&tick_upper_index)
+    {
+        return Err(TICK_OUT_OF_BOUNDS);
+    }
+
+    if !is_tick_initializable(tick_lower_index, tick_spacing)
+        || !is_tick_initializable(tick_upper_index, tick_spacing)
+    {
+        return Err(INVALID_TICK_RANGE);
+    }
+
+    Ok(())
 }
 
 /// Check if a tick is initializable.
@@ -92,8 +148,71 @@ pub fn try_get_amount_delta(
     result.try_into().map_err(|_| AMOUNT_EXCEEDS_MAX_U64)
 }
 
+/// Calculate the token0 and token1 amounts required for a liquidity delta across a range.
+///
+/// # Parameters
+/// - `current_sqrt_price`: The current square root price of the pool
+/// - `sqrt_price_lower`: The square root price at the lower tick of the range
+/// - `sqrt_price_upper`: The square root price at the upper tick of the range
+/// - `liquidity_delta`: The signed liquidity delta being applied to the range
+/// - `round_up`: Whether to round up or not
+///
+/// # Returns
+/// - `(u64, u64)`: The token0 and token1 amounts for the delta
+pub fn try_get_amounts_for_delta(
+    current_sqrt_price: u128,
+    sqrt_price_lower: u128,
+    sqrt_price_upper: u128,
+    liquidity_delta: i128,
+    round_up: bool,
+) -> Result<(u64, u64), ErrorCode> {
+    let liquidity = liquidity_delta.unsigned_abs();
+
+    if current_sqrt_price < sqrt_price_lower {
+        let amount_0 =
+            try_get_amount_delta(sqrt_price_lower, sqrt_price_upper, liquidity, round_up)?;
+        Ok((amount_0, 0))
+    } else if current_sqrt_price >= sqrt_price_upper {
+        let amount_1 =
+            try_get_amount_1_delta(sqrt_price_lower, sqrt_price_upper, liquidity, round_up)?;
+        Ok((0, amount_1))
+    } else {
+        let amount_0 =
+            try_get_amount_delta(current_sqrt_price, sqrt_price_upper, liquidity, round_up)?;
+        let amount_1 =
+            try_get_amount_1_delta(sqrt_price_lower, current_sqrt_price, liquidity, round_up)?;
+        Ok((amount_0, amount_1))
+    }
+}
+
 // Private functions
 
+pub(crate) fn try_get_amount_1_delta(
+    sqrt_price_1: u128,
+    sqrt_price_2: u128,
+    liquidity: u128,
+    round_up: bool,
+) -> Result<u64, ErrorCode> {
+    let (sqrt_price_lower, sqrt_price_upper) = order_prices(sqrt_price_1, sqrt_price_2);
+    let sqrt_price_diff = sqrt_price_upper - sqrt_price_lower;
+
+    let product: U256 = <U256>::from(liquidity)
+        .checked_mul(sqrt_price_diff.into())
+        .ok_or(ARITHMETIC_OVERFLOW)?;
+
+    let denominator: U256 = <U256>::from(1u8) << 64;
+    let quotient = product / denominator;
+    let remainder = product % denominator;
+
+    let result = if round_up && remainder != 0 {
+        quotient + 1
+    } else {
+        quotient
+    };
+
+    result.try_into().map_err(|_| AMOUNT_EXCEEDS_MAX_U64)
+}
+
 fn order_prices(a: u128, b: u128) -> (u128, u128) {
     if a < b {
         (a, b)
@@ -0,0 +1,247 @@
+use crate::{try_get_amount_1_delta, try_get_amount_delta, ErrorCode, ARITHMETIC_OVERFLOW};
+use ethnum::U256;
+
+/// The denominator `fee_rate` is expressed over, i.e. a `fee_rate` of `3000` is 0.3%.
+pub const FEE_RATE_DENOMINATOR: u32 = 1_000_000;
+
+/// Get the next sqrt price given an input amount of token0 or token1.
+///
+/// # Parameters
+/// - `sqrt_price`: The current square root price
+/// - `liquidity`: The current liquidity
+/// - `amount_in`: The amount being swapped in
+/// - `a_to_b`: Whether the input amount is token0 (swapping a for b)
+///
+/// # Returns
+/// - `u128`: The next square root price after the input is applied
+pub fn get_next_sqrt_price_from_input(
+    sqrt_price: u128,
+    liquidity: u128,
+    amount_in: u64,
+    a_to_b: bool,
+) -> Result<u128, ErrorCode> {
+    assert!(liquidity > 0, "liquidity must be non-zero");
+
+    if a_to_b {
+        get_next_sqrt_price_from_amount_0_round_up(sqrt_price, liquidity, amount_in, true)
+    } else {
+        get_next_sqrt_price_from_amount_1_round_down(sqrt_price, liquidity, amount_in, true)
+    }
+}
+
+/// Get the next sqrt price given an output amount of token0 or token1.
+///
+/// # Parameters
+/// - `sqrt_price`: The current square root price
+/// - `liquidity`: The current liquidity
+/// - `amount_out`: The amount being swapped out
+/// - `a_to_b`: Whether the output amount is token1 (swapping a for b)
+///
+/// # Returns
+/// - `u128`: The next square root price after the output is applied
+pub fn get_next_sqrt_price_from_output(
+    sqrt_price: u128,
+    liquidity: u128,
+    amount_out: u64,
+    a_to_b: bool,
+) -> Result<u128, ErrorCode> {
+    assert!(liquidity > 0, "liquidity must be non-zero");
+
+    if a_to_b {
+        get_next_sqrt_price_from_amount_1_round_down(sqrt_price, liquidity, amount_out, false)
+    } else {
+        get_next_sqrt_price_from_amount_0_round_up(sqrt_price, liquidity, amount_out, false)
+    }
+}
+
+/// Compute a single swap step toward a tick boundary.
+///
+/// # Parameters
+/// - `current_sqrt_price`: The current square root price
+/// - `target_sqrt_price`: The square root price of the tick boundary to swap towards
+/// - `liquidity`: The current liquidity
+/// - `amount_remaining`: The amount left to swap, as an input if `by_amount_in` else as an output
+/// - `fee_rate`: The fee rate, expressed as parts per `FEE_RATE_DENOMINATOR`
+/// - `by_amount_in`: Whether `amount_remaining` is an input (exact-in) or output (exact-out) amount
+///
+/// # Returns
+/// - `(u128, u64, u64, u64)`: The resulting square root price, and the input, output and fee amounts for the step
+pub fn compute_swap_step(
+    current_sqrt_price: u128,
+    target_sqrt_price: u128,
+    liquidity: u128,
+    amount_remaining: u64,
+    fee_rate: u32,
+    by_amount_in: bool,
+) -> Result<(u128, u64, u64, u64), ErrorCode> {
+    let a_to_b = current_sqrt_price >= target_sqrt_price;
+
+    let mut amount_in: u64 = 0;
+    let mut amount_out: u64 = 0;
+
+    let next_sqrt_price = if by_amount_in {
+        let amount_remaining_less_fee = mul_div_u64(
+            amount_remaining,
+            FEE_RATE_DENOMINATOR - fee_rate,
+            FEE_RATE_DENOMINATOR,
+        )?;
+        amount_in = if a_to_b {
+            try_get_amount_delta(target_sqrt_price, current_sqrt_price, liquidity, true)?
+        } else {
+            try_get_amount_1_delta(current_sqrt_price, target_sqrt_price, liquidity, true)?
+        };
+        if amount_remaining_less_fee >= amount_in {
+            target_sqrt_price
+        } else {
+            get_next_sqrt_price_from_input(
+                current_sqrt_price,
+                liquidity,
+                amount_remaining_less_fee,
+                a_to_b,
+            )?
+        }
+    } else {
+        amount_out = if a_to_b {
+            try_get_amount_1_delta(target_sqrt_price, current_sqrt_price, liquidity, false)?
+        } else {
+            try_get_amount_delta(current_sqrt_price, target_sqrt_price, liquidity, false)?
+        };
+        if amount_remaining >= amount_out {
+            target_sqrt_price
+        } else {
+            get_next_sqrt_price_from_output(
+                current_sqrt_price,
+                liquidity,
+                amount_remaining,
+                a_to_b,
+            )?
+        }
+    };
+
+    let reached_target = next_sqrt_price == target_sqrt_price;
+
+    if a_to_b {
+        amount_in = if reached_target && by_amount_in {
+            amount_in
+        } else {
+            try_get_amount_delta(next_sqrt_price, current_sqrt_price, liquidity, true)?
+        };
+        amount_out = if reached_target && !by_amount_in {
+            amount_out
+        } else {
+            try_get_amount_1_delta(next_sqrt_price, current_sqrt_price, liquidity, false)?
+        };
+    } else {
+        amount_in = if reached_target && by_amount_in {
+            amount_in
+        } else {
+            try_get_amount_1_delta(current_sqrt_price, next_sqrt_price, liquidity, true)?
+        };
+        amount_out = if reached_target && !by_amount_in {
+            amount_out
+        } else {
+            try_get_amount_delta(current_sqrt_price, next_sqrt_price, liquidity, false)?
+        };
+    }
+
+    let fee_amount = if by_amount_in {
+        mul_div_round_up_u64(amount_in, fee_rate, FEE_RATE_DENOMINATOR - fee_rate)?
+    } else {
+        mul_div_round_up_u64(amount_in, fee_rate, FEE_RATE_DENOMINATOR)?
+    };
+
+    Ok((next_sqrt_price, amount_in, amount_out, fee_amount))
+}
+
+// Private functions
+
+fn mul_div_u64(amount: u64, numerator: u32, denominator: u32) -> Result<u64, ErrorCode> {
+    let product: U256 = <U256>::from(amount)
+        .checked_mul(numerator.into())
+        .ok_or(ARITHMETIC_OVERFLOW)?;
+
+    (product / <U256>::from(denominator))
+        .try_into()
+        .map_err(|_| ARITHMETIC_OVERFLOW)
+}
+
+fn mul_div_round_up_u64(amount: u64, numerator: u32, denominator: u32) -> Result<u64, ErrorCode> {
+    let product: U256 = <U256>::from(amount)
+        .checked_mul(numerator.into())
+        .ok_or(ARITHMETIC_OVERFLOW)?;
+
+    let result = div_round_up(product, denominator.into())?;
+    u64::try_from(result).map_err(|_| ARITHMETIC_OVERFLOW)
+}
+
+fn get_next_sqrt_price_from_amount_0_round_up(
+    sqrt_price: u128,
+    liquidity: u128,
+    amount: u64,
+    add: bool,
+) -> Result<u128, ErrorCode> {
+    if amount == 0 {
+        return Ok(sqrt_price);
+    }
+
+    let liquidity_shifted: U256 = <U256>::from(liquidity) << 64;
+
+    if add {
+        if let Some(product) = <U256>::from(amount).checked_mul(sqrt_price.into()) {
+            if let Some(denominator) = liquidity_shifted.checked_add(product) {
+                return mul_div_round_up(liquidity_shifted, sqrt_price.into(), denominator);
+            }
+        }
+
+        // Fall back to a division-first form when `amount * sqrt_price` overflows U256.
+        let denominator = (liquidity_shifted / <U256>::from(sqrt_price))
+            .checked_add(amount.into())
+            .ok_or(ARITHMETIC_OVERFLOW)?;
+        div_round_up(liquidity.into(), denominator)
+    } else {
+        let product = <U256>::from(amount)
+            .checked_mul(sqrt_price.into())
+            .ok_or(ARITHMETIC_OVERFLOW)?;
+        let denominator = liquidity_shifted
+            .checked_sub(product)
+            .ok_or(ARITHMETIC_OVERFLOW)?;
+        mul_div_round_up(liquidity_shifted, sqrt_price.into(), denominator)
+    }
+}
+
+fn get_next_sqrt_price_from_amount_1_round_down(
+    sqrt_price: u128,
+    liquidity: u128,
+    amount: u64,
+    add: bool,
+) -> Result<u128, ErrorCode> {
+    let amount_shifted: U256 = <U256>::from(amount) << 64;
+
+    if add {
+        let quotient = div_round_down(amount_shifted, liquidity.into())?;
+        sqrt_price.checked_add(quotient).ok_or(ARITHMETIC_OVERFLOW)
+    } else {
+        let quotient = div_round_up(amount_shifted, liquidity.into())?;
+        sqrt_price.checked_sub(quotient).ok_or(ARITHMETIC_OVERFLOW)
+    }
+}
+
+fn mul_div_round_up(a: U256, b: U256, denominator: U256) -> Result<u128, ErrorCode> {
+    let product = a.checked_mul(b).ok_or(ARITHMETIC_OVERFLOW)?;
+    div_round_up(product, denominator)
+}
+
+fn div_round_down(numerator: U256, denominator: U256) -> Result<u128, ErrorCode> {
+    (numerator / denominator)
+        .try_into()
+        .map_err(|_| ARITHMETIC_OVERFLOW)
+}
+
+fn div_round_up(numerator: U256, denominator: U256) -> Result<u128, ErrorCode> {
+    let quotient = numerator / denominator;
+    let remainder = numerator % denominator;
+
+    let result = if remainder != 0 { quotient + 1 } else { quotient };
+
+    result.try_into().map_err(|_| ARITHMETIC_OVERFLOW)
+}
@@ -0,0 +1,63 @@
+use crate::{ErrorCode, ARITHMETIC_OVERFLOW};
+
+/// The state of an initialized tick, tracking liquidity and fee growth on either side of it.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Tick {
+    pub liquidity_gross: u128,
+    pub liquidity_net: i128,
+    pub fee_growth_outside_a: u128,
+    pub fee_growth_outside_b: u128,
+}
+
+impl Tick {
+    /// Apply a position's liquidity delta to this tick.
+    ///
+    /// # Parameters
+    /// - `liquidity_delta`: The signed liquidity delta being applied at this tick
+    /// - `upper`: Whether this tick is the upper bound of the position
+    ///
+    /// # Returns
+    /// - `bool`: Whether the tick flipped between initialized and uninitialized
+    pub fn update(&mut self, liquidity_delta: i128, upper: bool) -> Result<bool, ErrorCode> {
+        let liquidity_gross_before = self.liquidity_gross;
+        let liquidity_gross_after = if liquidity_delta >= 0 {
+            liquidity_gross_before
+                .checked_add(liquidity_delta as u128)
+                .ok_or(ARITHMETIC_OVERFLOW)?
+        } else {
+            liquidity_gross_before
+                .checked_sub(liquidity_delta.unsigned_abs())
+                .ok_or(ARITHMETIC_OVERFLOW)?
+        };
+
+        let liquidity_net_delta = if upper {
+            -liquidity_delta
+        } else {
+            liquidity_delta
+        };
+
+        self.liquidity_net = self
+            .liquidity_net
+            .checked_add(liquidity_net_delta)
+            .ok_or(ARITHMETIC_OVERFLOW)?;
+        self.liquidity_gross = liquidity_gross_after;
+
+        Ok((liquidity_gross_before == 0) != (liquidity_gross_after == 0))
+    }
+
+    /// Cross the tick during a swap, flipping its fee growth outside to the global-minus-outside
+    /// form.
+    ///
+    /// # Parameters
+    /// - `fee_growth_global_a`: The current global fee growth for token A
+    /// - `fee_growth_global_b`: The current global fee growth for token B
+    ///
+    /// # Returns
+    /// - `i128`: The liquidity net to apply to the pool's active liquidity
+    pub fn cross(&mut self, fee_growth_global_a: u128, fee_growth_global_b: u128) -> i128 {
+        self.fee_growth_outside_a = fee_growth_global_a.wrapping_sub(self.fee_growth_outside_a);
+        self.fee_growth_outside_b = fee_growth_global_b.wrapping_sub(self.fee_growth_outside_b);
+
+        self.liquidity_net
+    }
+}
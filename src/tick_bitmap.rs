@@ -0,0 +1,78 @@
+use ethnum::U256;
+
+/// Split a compressed tick index into the tick bitmap word and bit it lives in.
+///
+/// # Parameters
+/// - `compressed`: The tick index divided by the tick spacing
+///
+/// # Returns
+/// - `(i16, u8)`: The bitmap word index and the bit index within that word
+pub fn tick_position(compressed: i32) -> (i16, u8) {
+    let word_index = (compressed >> 8) as i16;
+    let bit_index = (compressed & 0xff) as u8;
+    (word_index, bit_index)
+}
+
+/// Find the next initialized tick within the given bitmap word, searching either to the left
+/// (`lte`) or to the right of `tick`.
+///
+/// # Parameters
+/// - `bitmap_word`: The tick bitmap word to search: the word of the compressed `tick` when
+///   `lte`, otherwise the word of the compressed `tick` plus one
+/// - `tick`: The starting tick index
+/// - `tick_spacing`: The tick spacing of the pool
+/// - `lte`: Whether to search for the next initialized tick less than or equal to `tick`
+///
+/// # Returns
+/// - `(i32, bool)`: The next tick index within the word, and whether it is actually initialized
+pub fn next_initialized_tick_within_one_word(
+    bitmap_word: U256,
+    tick: i32,
+    tick_spacing: u16,
+    lte: bool,
+) -> (i32, bool) {
+    let tick_spacing_i32 = tick_spacing as i32;
+    let mut compressed = tick / tick_spacing_i32;
+    if tick < 0 && tick % tick_spacing_i32 != 0 {
+        compressed -= 1;
+    }
+
+    if lte {
+        let (_, bit_index) = tick_position(compressed);
+        let mask = ((<U256>::from(1u8) << (bit_index as u32)) - <U256>::from(1u8))
+            | (<U256>::from(1u8) << (bit_index as u32));
+        let masked = bitmap_word & mask;
+
+        let initialized = masked != 0;
+        let next = if initialized {
+            compressed - (bit_index as i32 - most_significant_bit(masked) as i32)
+        } else {
+            compressed - bit_index as i32
+        };
+
+        (next * tick_spacing_i32, initialized)
+    } else {
+        let (_, bit_index) = tick_position(compressed + 1);
+        let mask = !((<U256>::from(1u8) << (bit_index as u32)) - <U256>::from(1u8));
+        let masked = bitmap_word & mask;
+
+        let initialized = masked != 0;
+        let next = if initialized {
+            compressed + 1 + (least_significant_bit(masked) as i32 - bit_index as i32)
+        } else {
+            compressed + 1 + (u8::MAX as i32 - bit_index as i32)
+        };
+
+        (next * tick_spacing_i32, initialized)
+    }
+}
+
+// Private functions
+
+fn most_significant_bit(word: U256) -> u8 {
+    (255 - word.leading_zeros()) as u8
+}
+
+fn least_significant_bit(word: U256) -> u8 {
+    word.trailing_zeros() as u8
+}
@@ -0,0 +1,128 @@
+use crate::{ErrorCode, ARITHMETIC_OVERFLOW};
+use ethnum::U256;
+
+/// Error returned when a tick index or sqrt price falls outside `[MIN_TICK, MAX_TICK]`
+/// or `[MIN_SQRT_PRICE, MAX_SQRT_PRICE]`.
+pub const TICK_OUT_OF_BOUNDS: ErrorCode = 9005;
+
+pub const MIN_TICK: i32 = -443636;
+pub const MAX_TICK: i32 = 443636;
+
+/// `tick_to_sqrt_price(MIN_TICK)`, kept in sync with the bit-decomposition constants below.
+pub const MIN_SQRT_PRICE: u128 = 4295048017;
+/// `tick_to_sqrt_price(MAX_TICK)`, kept in sync with the bit-decomposition constants below.
+pub const MAX_SQRT_PRICE: u128 = 79226673515401279992447579062;
+
+const BIT_PRECISION: u32 = 14;
+const LOG_B_2_X32: i128 = 59543866431248;
+const LOG_B_P_ERR_MARGIN_LOWER_X64: i128 = 184467440737095516;
+const LOG_B_P_ERR_MARGIN_UPPER_X64: i128 = 15793534762490258745;
+
+/// Convert a tick index to its corresponding square root price.
+///
+/// # Parameters
+/// - `tick`: The tick index to convert
+///
+/// # Returns
+/// - `u128`: The square root price, in Q64.64 representation
+pub fn tick_to_sqrt_price(tick: i32) -> Result<u128, ErrorCode> {
+    if !(MIN_TICK..=MAX_TICK).contains(&tick) {
+        return Err(TICK_OUT_OF_BOUNDS);
+    }
+
+    let abs_tick = tick.unsigned_abs();
+
+    let mut ratio: U256 = if abs_tick & 0x1 != 0 {
+        U256::from(0xfffcb933bd6fad37aa2d162d1a594001u128)
+    } else {
+        <U256>::from(1u128) << 128
+    };
+
+    const RATIOS: [(u32, u128); 18] = [
+        (0x2, 0xfff97272373d413259a46990580e213a),
+        (0x4, 0xfff2e50f5f656932ef12357cf3c7fdcc),
+        (0x8, 0xffe5caca7e10e4e61c3624eaa0941cd0),
+        (0x10, 0xffcb9843d60f6159c9db58835c926644),
+        (0x20, 0xff973b41fa98c081472e6896dfb254c0),
+        (0x40, 0xff2ea16466c96a3843ec78b326b52861),
+        (0x80, 0xfe5dee046a99a2a811c461f1969c3053),
+        (0x100, 0xfcbe86c7900a88aedcffc83b479aa3a4),
+        (0x200, 0xf987a7253ac413176f2b074cf7815e54),
+        (0x400, 0xf3392b0822b70005940c7a398e4b70f3),
+        (0x800, 0xe7159475a2c29b7443b29c7fa6e889d9),
+        (0x1000, 0xd097f3bdfd2022b8845ad8f792aa5825),
+        (0x2000, 0xa9f746462d870fdf8a65dc1f90e061e5),
+        (0x4000, 0x70d869a156d2a1b890bb3df62baf32f7),
+        (0x8000, 0x31be135f97d08fd981231505542fcfa6),
+        (0x10000, 0x9aa508b5b7a84e1c677de54f3e99bc9),
+        (0x20000, 0x5d6af8dedb81196699c329225ee604),
+        (0x40000, 0x2216e584f5fa1ea926041bedfe98),
+    ];
+
+    for (mask, constant) in RATIOS {
+        if abs_tick & mask != 0 {
+            ratio = (ratio * <U256>::from(constant)) >> 128;
+        }
+    }
+
+    if tick > 0 {
+        ratio = <U256>::MAX / ratio;
+    }
+
+    let shifted: U256 = ratio.checked_shr(64).ok_or(ARITHMETIC_OVERFLOW)?;
+    let remainder = ratio & <U256>::from(u64::MAX);
+    let rounded = if remainder != 0 { shifted + 1 } else { shifted };
+
+    rounded.try_into().map_err(|_| TICK_OUT_OF_BOUNDS)
+}
+
+/// Convert a square root price to the largest tick index whose price does not exceed it.
+///
+/// # Parameters
+/// - `sqrt_price`: The square root price, in Q64.64 representation
+///
+/// # Returns
+/// - `i32`: The tick index
+pub fn sqrt_price_to_tick(sqrt_price: u128) -> Result<i32, ErrorCode> {
+    if !(MIN_SQRT_PRICE..=MAX_SQRT_PRICE).contains(&sqrt_price) {
+        return Err(TICK_OUT_OF_BOUNDS);
+    }
+
+    let msb = 127 - sqrt_price.leading_zeros();
+    let log2p_integer_x32 = (msb as i128 - 64) << 32;
+
+    let mut r: u128 = if msb >= 64 {
+        sqrt_price >> (msb - 63)
+    } else {
+        sqrt_price << (63 - msb)
+    };
+
+    let mut bit: i128 = 0x8000_0000;
+    let mut log2p_fraction_x32: i128 = 0;
+    let mut precision = 0;
+
+    while bit > 0 && precision < BIT_PRECISION {
+        r *= r;
+        let is_r_more_than_two = (r >> 127) as i128;
+        r >>= 63 + is_r_more_than_two as u32;
+        log2p_fraction_x32 += bit * is_r_more_than_two;
+        bit >>= 1;
+        precision += 1;
+    }
+
+    let log2p_x32 = log2p_integer_x32 + log2p_fraction_x32;
+    let logbp_x64 = log2p_x32 * LOG_B_2_X32;
+
+    let tick_low = ((logbp_x64 - LOG_B_P_ERR_MARGIN_LOWER_X64) >> 64) as i32;
+    let tick_high = ((logbp_x64 + LOG_B_P_ERR_MARGIN_UPPER_X64) >> 64) as i32;
+
+    if tick_low == tick_high {
+        return Ok(tick_low);
+    }
+
+    if tick_to_sqrt_price(tick_high)? <= sqrt_price {
+        Ok(tick_high)
+    } else {
+        Ok(tick_low)
+    }
+}